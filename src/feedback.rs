@@ -0,0 +1,94 @@
+use crate::{guardian_chat_silent, GameState};
+use colored::*;
+use rig::streaming::StreamingChat;
+use std::error::Error;
+
+/// How closely a single guessed word relates to the canonical answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    None,
+    Exists,
+    Matched,
+}
+
+fn normalize(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// Scores each word of `guess` against `answer`: an exact word match is `Matched`, a
+/// substring/stem overlap with some answer word is `Exists`, otherwise `None`.
+pub fn evaluate_overlap(guess: &str, answer: &str) -> Vec<(String, Status)> {
+    let answer_words: Vec<String> = answer
+        .split_whitespace()
+        .map(normalize)
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    guess
+        .split_whitespace()
+        .map(|word| {
+            let normalized = normalize(word);
+            let status = if normalized.is_empty() {
+                Status::None
+            } else if answer_words.iter().any(|a| *a == normalized) {
+                Status::Matched
+            } else if answer_words
+                .iter()
+                .any(|a| a.contains(&normalized) || normalized.contains(a.as_str()))
+            {
+                Status::Exists
+            } else {
+                Status::None
+            };
+            (word.to_string(), status)
+        })
+        .collect()
+}
+
+/// Renders `scored` with the repo's usual `colored` styling: green for exact matches,
+/// yellow for partial/stem overlap, dim for words unrelated to the answer.
+pub fn render_overlap(scored: &[(String, Status)]) -> String {
+    scored
+        .iter()
+        .map(|(word, status)| match status {
+            Status::Matched => word.green().to_string(),
+            Status::Exists => word.yellow().to_string(),
+            Status::None => word.dimmed().to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Asks the guardian for the canonical answer once (caching it in `state.answer` without
+/// ever printing it), then shows the player's guess colorized by how closely each word
+/// matches it — a Wordle-style proximity cue that stops short of handing over the solution.
+pub async fn feedback_on_guess(
+    riddler: &dyn StreamingChat,
+    guess: &str,
+    state: &mut GameState,
+) -> Result<(), Box<dyn Error>> {
+    if state.answer.is_none() {
+        let answer = guardian_chat_silent(
+            riddler,
+            "Please state the canonical answer to the riddle in a short phrase, nothing more.",
+            state.history.clone(),
+            "Failed to determine the canonical answer for feedback",
+            "The Guardian quietly weighs how close you are...",
+        )
+        .await?;
+        state.answer = Some(answer.trim().to_string());
+    }
+
+    let answer = state.answer.clone().unwrap_or_default();
+    if answer.is_empty() {
+        return Ok(());
+    }
+
+    let scored = evaluate_overlap(guess, &answer);
+    println!("{}", render_overlap(&scored));
+
+    Ok(())
+}
@@ -2,15 +2,22 @@ use chrono::Local;
 use colored::*;
 use dialoguer::{theme::ColorfulTheme, Input, Select};
 use dotenv::dotenv;
+use futures::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
 use rig::{
-    completion::{Chat, Message},
-    providers::openai,
+    completion::Message,
+    streaming::{StreamingChat, StreamingChoice},
 };
 use serde::{Deserialize, Serialize};
-use std::{error::Error, fs, path::Path, thread, time::Duration, io};
+use std::io::Write;
+use std::{error::Error, thread, time::Duration, io};
+
+mod feedback;
+mod providers;
+mod review;
+mod saves;
+mod story;
 
-const SAVE_FILE: &str = "riddler_save.json";
 const DIFFICULTY_DESCRIPTIONS: [&str; 3] = [
     "Easy: Simple riddles suitable for beginners",
     "Medium: Challenging riddles that will make you think",
@@ -28,6 +35,7 @@ const TITLE_ART: &str = r#"
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 struct GameState {
+    slot: String,
     difficulty: usize,
     current_riddle: String,
     attempts: usize,
@@ -35,11 +43,15 @@ struct GameState {
     history: Vec<Message>,
     score: i32,
     date_started: String,
+    config: providers::RiddlerConfig,
+    #[serde(default)]
+    answer: Option<String>,
 }
 
 impl Default for GameState {
     fn default() -> Self {
         Self {
+            slot: Local::now().format("riddle-%Y%m%d-%H%M%S").to_string(),
             difficulty: 1,
             current_riddle: String::new(),
             attempts: 0,
@@ -47,28 +59,12 @@ impl Default for GameState {
             history: Vec::new(),
             score: 0,
             date_started: Local::now().to_rfc3339(),
+            config: providers::RiddlerConfig::default(),
+            answer: None,
         }
     }
 }
 
-fn show_spinner(message: &str, duration_ms: u64) {
-    let pb = ProgressBar::new_spinner();
-    pb.set_style(
-        ProgressStyle::default_spinner()
-            .tick_chars("⠁⠂⠄⡀⢀⠠⠐⠈ ")
-            .template("{spinner:.green} {msg}")
-            .unwrap(),
-    );
-    pb.set_message(message.to_string());
-    
-    for _ in 0..duration_ms / 100 {
-        pb.tick();
-        thread::sleep(Duration::from_millis(100));
-    }
-    
-    pb.finish_and_clear();
-}
-
 fn print_header() {
     let title = TITLE_ART.bright_cyan().bold();
     println!("\n{}", title);
@@ -92,33 +88,7 @@ fn print_fancy_message(message: &str, color: &str) {
 }
 
 fn save_game(state: &GameState) -> Result<(), Box<dyn Error>> {
-    // Create a temporary file to write to first
-    let temp_file = format!("{}.tmp", SAVE_FILE);
-    let json = serde_json::to_string_pretty(state)?;
-    
-    // Write to the temporary file first
-    fs::write(&temp_file, &json)?;
-    
-    // Then rename the temporary file to the actual save file
-    // This helps prevent corruption if the program crashes during the write
-    if Path::new(&temp_file).exists() {
-        if Path::new(SAVE_FILE).exists() {
-            fs::remove_file(SAVE_FILE)?;
-        }
-        fs::rename(&temp_file, SAVE_FILE)?;
-    }
-    
-    Ok(())
-}
-
-fn load_game() -> Result<GameState, Box<dyn Error>> {
-    if Path::new(SAVE_FILE).exists() {
-        let json = fs::read_to_string(SAVE_FILE)?;
-        let state: GameState = serde_json::from_str(&json)?;
-        Ok(state)
-    } else {
-        Ok(GameState::default())
-    }
+    saves::save_slot(&state.slot, state)
 }
 
 fn get_difficulty_prompt(difficulty: usize) -> &'static str {
@@ -130,70 +100,188 @@ fn get_difficulty_prompt(difficulty: usize) -> &'static str {
     }
 }
 
-fn calculate_score(difficulty: usize, attempts: usize, hints: usize) -> i32 {
+/// Escalating hint instructions and their score cost, in order. The last tier is
+/// explicitly forbidden from leaking the literal answer.
+const HINT_TIERS: [(&str, i32); 3] = [
+    (
+        "Give the user a vague, thematic nudge about the riddle, without pointing at the answer.",
+        5,
+    ),
+    (
+        "Point the user toward the general category or domain the answer belongs to, but do not reveal the answer.",
+        10,
+    ),
+    (
+        "Reveal a strong, concrete clue that makes the answer much easier to guess, but do NOT state the literal answer under any circumstances.",
+        20,
+    ),
+];
+
+fn hint_penalty(hints_used: usize) -> i32 {
+    HINT_TIERS
+        .iter()
+        .take(hints_used)
+        .map(|(_, cost)| cost)
+        .sum()
+}
+
+fn calculate_score(difficulty: usize, attempts: usize, hints_used: usize) -> i32 {
     let base_score = match difficulty {
         0 => 10,
         1 => 25,
         2 => 50,
         _ => 25,
     };
-    
+
     let attempt_penalty = (attempts as i32 - 1).max(0) * 5;
-    let hint_penalty = hints as i32 * 10;
-    
-    (base_score - attempt_penalty - hint_penalty).max(0)
+
+    (base_score - attempt_penalty - hint_penalty(hints_used)).max(0)
 }
 
-/// Helper function to handle API calls with consistent error handling
-async fn guardian_chat<C>(
-    riddler: &C,
+/// Helper function to handle API calls with consistent error handling.
+///
+/// Streams the guardian's reply token by token, keeping the spinner up only until the
+/// first token lands, then clearing it and printing chunks live as they arrive. The full
+/// reply is buffered and returned so callers can append it to `state.history`.
+pub(crate) async fn guardian_chat(
+    riddler: &dyn StreamingChat,
     prompt: &str,
     history: Vec<Message>,
     error_message: &str,
     spinner_message: &str,
-    spinner_duration: u64,
-) -> Result<String, Box<dyn Error>>
-where
-    C: Chat,
-{
-    show_spinner(spinner_message, spinner_duration);
-    
-    match riddler.chat(prompt, history).await {
-        Ok(response) => Ok(response),
+) -> Result<String, Box<dyn Error>> {
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .tick_chars("⠁⠂⠄⡀⢀⠠⠐⠈ ")
+            .template("{spinner:.green} {msg}")
+            .unwrap(),
+    );
+    pb.set_message(spinner_message.to_string());
+    pb.enable_steady_tick(Duration::from_millis(100));
+
+    let fail = |pb: &ProgressBar, e: &dyn std::fmt::Display| {
+        pb.finish_and_clear();
+        print_fancy_message("The Ancient Guardian cannot respond...", "red");
+        println!("Error: {}", e);
+    };
+
+    let mut stream = match riddler.stream_chat(prompt, history).await {
+        Ok(stream) => stream,
+        Err(e) => {
+            fail(&pb, &e);
+            return Err(Box::new(io::Error::new(io::ErrorKind::Other, error_message)));
+        }
+    };
+
+    let mut response = String::new();
+    let mut spinner_cleared = false;
+
+    while let Some(chunk) = stream.next().await {
+        match chunk {
+            Ok(StreamingChoice::Message(text)) => {
+                if !spinner_cleared {
+                    pb.finish_and_clear();
+                    spinner_cleared = true;
+                }
+                print!("{}", text.bright_white());
+                io::stdout().flush()?;
+                response.push_str(&text);
+            }
+            Ok(_) => {}
+            Err(e) => {
+                fail(&pb, &e);
+                return Err(Box::new(io::Error::new(io::ErrorKind::Other, error_message)));
+            }
+        }
+    }
+
+    if !spinner_cleared {
+        pb.finish_and_clear();
+    }
+    println!();
+
+    Ok(response)
+}
+
+/// Like `guardian_chat`, but buffers the guardian's reply without printing it, for
+/// prompts whose answer must stay out of the transcript (e.g. the canonical answer
+/// used to compute closeness feedback on a wrong guess).
+pub(crate) async fn guardian_chat_silent(
+    riddler: &dyn StreamingChat,
+    prompt: &str,
+    history: Vec<Message>,
+    error_message: &str,
+    spinner_message: &str,
+) -> Result<String, Box<dyn Error>> {
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .tick_chars("⠁⠂⠄⡀⢀⠠⠐⠈ ")
+            .template("{spinner:.green} {msg}")
+            .unwrap(),
+    );
+    pb.set_message(spinner_message.to_string());
+    pb.enable_steady_tick(Duration::from_millis(100));
+
+    let fail = |pb: &ProgressBar, e: &dyn std::fmt::Display| {
+        pb.finish_and_clear();
+        print_fancy_message("The Ancient Guardian cannot respond...", "red");
+        println!("Error: {}", e);
+    };
+
+    let mut stream = match riddler.stream_chat(prompt, history).await {
+        Ok(stream) => stream,
         Err(e) => {
-            print_fancy_message("The Ancient Guardian cannot respond...", "red");
-            println!("Error: {}", e);
-            Err(Box::new(io::Error::new(
-                io::ErrorKind::Other,
-                error_message,
-            )))
+            fail(&pb, &e);
+            return Err(Box::new(io::Error::new(io::ErrorKind::Other, error_message)));
+        }
+    };
+
+    let mut response = String::new();
+    while let Some(chunk) = stream.next().await {
+        match chunk {
+            Ok(StreamingChoice::Message(text)) => response.push_str(&text),
+            Ok(_) => {}
+            Err(e) => {
+                fail(&pb, &e);
+                return Err(Box::new(io::Error::new(io::ErrorKind::Other, error_message)));
+            }
         }
     }
+
+    pb.finish_and_clear();
+
+    Ok(response)
 }
 
 async fn start_new_game(
-    riddler: &impl Chat,
+    riddler: &dyn StreamingChat,
     difficulty: usize,
+    config: &providers::RiddlerConfig,
+    slot: String,
 ) -> Result<GameState, Box<dyn Error>> {
     let mut state = GameState {
+        slot,
         difficulty,
         date_started: Local::now().to_rfc3339(),
+        config: config.clone(),
         ..Default::default()
     };
-    
+
     // Create the riddle prompt based on difficulty
     let riddle_prompt = get_difficulty_prompt(difficulty);
-    
+
+    print_fancy_message("The Ancient Guardian speaks:", "yellow");
     let riddle = guardian_chat(
         riddler,
         riddle_prompt,
         vec![],
         "Failed to communicate with the Guardian",
         "The Ancient Guardian is thinking of a riddle...",
-        3000,
     )
     .await?;
-    
+
     state.current_riddle = riddle.clone();
     
     // Add to history
@@ -206,52 +294,59 @@ async fn start_new_game(
 }
 
 async fn get_hint(
-    riddler: &impl Chat,
+    riddler: &dyn StreamingChat,
     state: &mut GameState,
 ) -> Result<String, Box<dyn Error>> {
-    let hint_request = "XYZ".to_string();
+    if state.hints_used >= HINT_TIERS.len() {
+        print_fancy_message(
+            "The Guardian has shared all the hints it will allow; the rest is yours to puzzle out.",
+            "magenta",
+        );
+        return Ok(String::new());
+    }
+
+    let instruction = HINT_TIERS[state.hints_used].0;
     state.hints_used += 1;
-    
+
+    print_fancy_message("The Guardian whispers a hint:", "magenta");
     let hint = guardian_chat(
         riddler,
-        &hint_request,
+        instruction,
         state.history.clone(),
         "Failed to get a hint from the Guardian",
         "The Guardian is considering a hint...",
-        2000,
     )
     .await?;
-    
-    state.history.push(Message::user(&hint_request));
+
+    state.history.push(Message::user(instruction));
     state.history.push(Message::assistant(&hint));
-    
+
     save_game(state)?;
-    
+
     Ok(hint)
 }
 
 async fn check_guess(
-    riddler: &impl Chat,
+    riddler: &dyn StreamingChat,
     guess: &str,
     state: &mut GameState,
 ) -> Result<bool, Box<dyn Error>> {
     state.attempts += 1;
-    
+
     let ask_about_guess = format!(
         "Here is the user's answer: {}\nPlease answer exactly \"yes\" or \"no\" if this answer is satisfactory, nothing more.",
         guess
     );
-    
-    let judgement = guardian_chat(
+
+    let judgement = guardian_chat_silent(
         riddler,
         &ask_about_guess,
         state.history.clone(),
         "Failed to get judgment from the Guardian",
         "The Guardian is judging your answer...",
-        1500,
     )
     .await?;
-    
+
     // Trim and convert to lowercase for more reliable comparison
     let judgement_clean = judgement.trim().to_lowercase();
     let correct = judgement_clean == "yes" || judgement_clean == "yes.";
@@ -269,21 +364,21 @@ async fn check_guess(
 }
 
 async fn reveal_insight(
-    riddler: &impl Chat,
+    riddler: &dyn StreamingChat,
     state: &mut GameState,
 ) -> Result<String, Box<dyn Error>> {
     let insight_prompt = "Please provide the user with their deeply deserved insight";
-    
+
+    print_fancy_message("The Guardian reveals the promised wisdom:", "cyan");
     let insight = guardian_chat(
         riddler,
         insight_prompt,
         state.history.clone(),
         "Failed to get insight from the Guardian",
         "The Guardian is preparing your insight...",
-        3000,
     )
     .await?;
-    
+
     state.history.push(Message::user(insight_prompt));
     state.history.push(Message::assistant(&insight));
     
@@ -292,28 +387,197 @@ async fn reveal_insight(
     Ok(insight)
 }
 
+/// Quizzes the player on every riddle currently due for review, oldest first,
+/// regrading and rescheduling each one with SM-2 regardless of outcome.
+async fn run_review_session(
+    riddler: &dyn StreamingChat,
+    reviews: &mut Vec<review::ReviewItem>,
+) -> Result<(), Box<dyn Error>> {
+    let due = review::due_items(reviews);
+
+    if due.is_empty() {
+        print_fancy_message("No riddles are due for review right now.", "blue");
+        thread::sleep(Duration::from_secs(2));
+        return Ok(());
+    }
+
+    for item in due {
+        print_fancy_message("The Guardian recalls an old riddle:", "yellow");
+        println!("{}", item.riddle.bright_white());
+
+        let mut attempts = 0usize;
+        let correct = loop {
+            attempts += 1;
+            let guess: String = Input::with_theme(&ColorfulTheme::default())
+                .with_prompt("Your answer")
+                .interact_text()?;
+
+            let judge_prompt = format!(
+                "The riddle was: {}\nThe canonical answer is: {}\nThe user answered: {}\nDoes the user's answer match the canonical answer? Please answer exactly \"yes\" or \"no\", nothing more.",
+                item.riddle, item.answer, guess
+            );
+            let judgement = guardian_chat_silent(
+                riddler,
+                &judge_prompt,
+                vec![],
+                "Failed to get judgment from the Guardian",
+                "The Guardian is judging your answer...",
+            )
+            .await?;
+
+            let judgement_clean = judgement.trim().to_lowercase();
+            if judgement_clean == "yes" || judgement_clean == "yes." {
+                break true;
+            }
+
+            print_fancy_message("Not quite. Try again...", "red");
+            if attempts >= 3 {
+                break false;
+            }
+        };
+
+        let q = if correct {
+            review::quality_from_performance(attempts, 0)
+        } else {
+            1
+        };
+
+        if let Some(stored) = reviews.iter_mut().find(|r| r.riddle == item.riddle) {
+            review::schedule_review(stored, q);
+        }
+
+        if correct {
+            print_fancy_message("CORRECT! The memory is refreshed.", "green");
+        } else {
+            print_fancy_message("The answer was:", "cyan");
+            println!("{}", item.answer.bright_white());
+        }
+    }
+
+    review::save_reviews(reviews)?;
+    Ok(())
+}
+
+/// Plays a branching narrative campaign: solving each block's riddle advances the
+/// player along the story graph loaded from `path` until a terminal block is reached.
+async fn run_campaign(riddler: &dyn StreamingChat, path: &str) -> Result<(), Box<dyn Error>> {
+    let blocks = story::load_story(path)?;
+
+    let Some(start) = blocks.first() else {
+        print_fancy_message("This story file has no blocks to play.", "red");
+        return Ok(());
+    };
+
+    let mut campaign = story::CampaignState {
+        game: GameState {
+            slot: saves::CAMPAIGN_SLOT.to_string(),
+            ..Default::default()
+        },
+        current_block: start.id.clone(),
+    };
+
+    loop {
+        let Some(block) = story::find_block(&blocks, &campaign.current_block) else {
+            print_fancy_message("The story trails off into the unknown...", "red");
+            break;
+        };
+
+        print_fancy_message("The tale continues:", "blue");
+        println!("{}", block.narration.bright_white());
+
+        if block.options.is_empty() {
+            print_fancy_message("THE END", "cyan");
+            println!(
+                "\n{} {}",
+                "Final Score:".bright_yellow(),
+                campaign.game.score.to_string().bright_green()
+            );
+            break;
+        }
+
+        print_fancy_message("The Ancient Guardian speaks:", "yellow");
+        let riddle = guardian_chat(
+            riddler,
+            &block.riddle_prompt,
+            vec![],
+            "Failed to communicate with the Guardian",
+            "The Ancient Guardian is thinking of a riddle...",
+        )
+        .await?;
+
+        campaign.game.current_riddle = riddle.clone();
+        campaign.game.history = vec![Message::user(&block.riddle_prompt), Message::assistant(&riddle)];
+        campaign.game.attempts = 0;
+        campaign.game.hints_used = 0;
+        campaign.game.answer = None;
+
+        loop {
+            println!("\n{}", "-".repeat(50).bright_blue());
+            println!("Score: {}", campaign.game.score.to_string().green());
+            println!("{}", "-".repeat(50).bright_blue());
+
+            let guess: String = Input::with_theme(&ColorfulTheme::default())
+                .with_prompt("Your answer (type 'hint' for a hint, 'riddle' to see the riddle again)")
+                .interact_text()?;
+
+            if guess.trim().to_lowercase() == "hint" {
+                let _hint = get_hint(riddler, &mut campaign.game).await?;
+                continue;
+            }
+
+            if guess.trim().to_lowercase() == "riddle" {
+                print_fancy_message("The Guardian repeats the riddle:", "yellow");
+                println!("{}", campaign.game.current_riddle.bright_white());
+                continue;
+            }
+
+            let correct = check_guess(riddler, &guess, &mut campaign.game).await?;
+
+            if correct {
+                print_fancy_message("CORRECT!", "green");
+                campaign.current_block =
+                    story::pick_next_block(riddler, campaign.game.history.clone(), block).await?;
+                break;
+            } else {
+                print_fancy_message("INCORRECT!", "red");
+                println!("{}", "The Ancient Guardian shakes their head. Try again...".bright_red());
+                feedback::feedback_on_guess(riddler, &guess, &mut campaign.game).await?;
+            }
+        }
+
+        story::save_campaign(&campaign)?;
+    }
+
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     let _ = dotenv().ok();
-    let openai = openai::Client::from_env();
-    
-    let riddler = openai
-        .agent("gpt-4o")
-        .preamble("You are a guardian of an immense and powerful ancient secret. You are endowed with the unique ability to create incredibly challenging and intellectually stimulating riddles. You will ensure the user gets the riddle right before you let them get the treasure, which is actually a deep and stimulating truth relating to the riddle answer. Please do not provide a hint unless the user provides the secret code XYZ. Your responses should be mystical, ancient, and fitting for a wise guardian of secrets. For hints, be enigmatic but helpful.")
-        .temperature(0.9)
-        .build();
-    
+
+    let config = providers::select_config()?;
+    let riddler = providers::build_riddler(&config)?;
+
+    let mut reviews = review::load_reviews().unwrap_or_default();
+
     // Main game loop
     loop {
         print_header();
-        
-        let selections = vec!["Start New Game", "Continue Saved Game", "View Instructions", "Quit"];
+
+        let selections = vec![
+            "Start New Game",
+            "Campaign",
+            "Review",
+            "Continue Saved Game",
+            "View Instructions",
+            "Quit",
+        ];
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose an option:")
             .default(0)
             .items(&selections)
             .interact()?;
-        
+
         match selection {
             0 => {
                 // Start New Game
@@ -323,18 +587,22 @@ async fn main() -> Result<(), Box<dyn Error>> {
                     .default(1)
                     .items(&DIFFICULTY_DESCRIPTIONS)
                     .interact()?;
-                
-                let mut state = start_new_game(&riddler, difficulty).await?;
-                
-                print_fancy_message("The Ancient Guardian speaks:", "yellow");
-                println!("{}", state.current_riddle.bright_white());
-                
+
+                let default_slot = Local::now().format("riddle-%Y%m%d-%H%M%S").to_string();
+                let slot: String = Input::with_theme(&ColorfulTheme::default())
+                    .with_prompt("Name this save")
+                    .default(default_slot)
+                    .interact_text()?;
+
+                let mut state = start_new_game(&riddler, difficulty, &config, slot).await?;
+
                 // Riddle solving loop
                 loop {
                     println!("\n{}", "-".repeat(50).bright_blue());
-                    println!("Attempts: {} | Hints Used: {} | Score: {}", 
+                    println!("Attempts: {} | Hint Tier: {}/{} | Score: {}",
                              state.attempts.to_string().yellow(),
                              state.hints_used.to_string().yellow(),
+                             HINT_TIERS.len(),
                              state.score.to_string().green());
                     println!("{}", "-".repeat(50).bright_blue());
                     
@@ -343,40 +611,45 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .interact_text()?;
                     
                     if guess.trim().to_lowercase() == "hint" {
-                        let hint = get_hint(&riddler, &mut state).await?;
-                        print_fancy_message("The Guardian whispers a hint:", "magenta");
-                        println!("{}", hint.bright_white());
+                        let _hint = get_hint(&riddler, &mut state).await?;
                         continue;
                     }
-                    
+
                     if guess.trim().to_lowercase() == "riddle" {
                         print_fancy_message("The Guardian repeats the riddle:", "yellow");
                         println!("{}", state.current_riddle.bright_white());
                         continue;
                     }
-                    
+
                     // Check the guess
                     let correct = check_guess(&riddler, &guess, &mut state).await?;
-                    
+
                     if correct {
                         print_fancy_message("CORRECT!", "green");
                         println!("{}", "The Ancient Guardian nods in approval...".bright_green());
-                        
+
                         // Get the final insight
-                        let insight = reveal_insight(&riddler, &mut state).await?;
-                        
-                        print_fancy_message("The Guardian reveals the promised wisdom:", "cyan");
-                        println!("{}", insight.bright_white());
-                        
+                        let _insight = reveal_insight(&riddler, &mut state).await?;
+
+                        review::record_solved_riddle(
+                            &riddler,
+                            &state.current_riddle,
+                            state.history.clone(),
+                            state.attempts,
+                            state.hints_used,
+                            &mut reviews,
+                        )
+                        .await?;
+
                         println!("\n{} {}", "Final Score:".bright_yellow(), state.score.to_string().bright_green());
-                        
+
                         println!("\nWould you like to play another riddle?");
                         let play_again = Select::with_theme(&ColorfulTheme::default())
                             .with_prompt("Choose an option")
                             .default(0)
                             .items(&["Yes", "No"])
                             .interact()?;
-                        
+
                         if play_again == 0 {
                             break; // Break out of riddle loop to start a new game
                         } else {
@@ -385,34 +658,95 @@ async fn main() -> Result<(), Box<dyn Error>> {
                     } else {
                         print_fancy_message("INCORRECT!", "red");
                         println!("{}", "The Ancient Guardian shakes their head. Try again...".bright_red());
+                        feedback::feedback_on_guess(&riddler, &guess, &mut state).await?;
                     }
                 }
             }
             1 => {
-                // Continue Saved Game
-                match load_game() {
+                // Campaign: a branching story loaded from an external file
+                let path: String = Input::with_theme(&ColorfulTheme::default())
+                    .with_prompt("Path to story file")
+                    .default("story.toml".to_string())
+                    .interact_text()?;
+
+                if let Err(e) = run_campaign(&riddler, &path).await {
+                    print_fancy_message("The story could not be told...", "red");
+                    println!("Error: {}", e);
+                    thread::sleep(Duration::from_secs(2));
+                }
+            }
+            2 => {
+                // Review previously solved riddles due for spaced repetition
+                run_review_session(&riddler, &mut reviews).await?;
+            }
+            3 => {
+                // Continue Saved Game: browse named save slots
+                let slots = saves::list_slots()?;
+
+                if slots.is_empty() {
+                    print_fancy_message("No saved games found!", "red");
+                    thread::sleep(Duration::from_secs(2));
+                    continue;
+                }
+
+                let mut items: Vec<String> = slots
+                    .iter()
+                    .map(|s| {
+                        format!(
+                            "{} — {} | Score: {} | Started: {}",
+                            s.slot, DIFFICULTY_DESCRIPTIONS[s.difficulty], s.score, s.date_started
+                        )
+                    })
+                    .collect();
+                items.push("Delete a Save".to_string());
+                items.push("Back".to_string());
+
+                let choice = Select::with_theme(&ColorfulTheme::default())
+                    .with_prompt("Choose a saved game:")
+                    .default(0)
+                    .items(&items)
+                    .interact()?;
+
+                if choice == slots.len() + 1 {
+                    // Back
+                    continue;
+                }
+
+                if choice == slots.len() {
+                    // Delete a Save
+                    let names: Vec<&str> = slots.iter().map(|s| s.slot.as_str()).collect();
+                    let to_delete = Select::with_theme(&ColorfulTheme::default())
+                        .with_prompt("Delete which save?")
+                        .default(0)
+                        .items(&names)
+                        .interact()?;
+                    saves::delete_slot(&slots[to_delete].slot)?;
+                    print_fancy_message("Save deleted.", "yellow");
+                    thread::sleep(Duration::from_secs(1));
+                    continue;
+                }
+
+                match saves::load_slot(&slots[choice].slot) {
                     Ok(mut state) => {
-                        if state.current_riddle.is_empty() {
-                            print_fancy_message("No saved game found!", "red");
-                            thread::sleep(Duration::from_secs(2));
-                            continue;
-                        }
-                        
+                        // Reuse whichever guardian this save was started with
+                        let riddler = providers::build_riddler(&state.config)?;
+
                         print_fancy_message("Continuing your quest...", "blue");
-                        println!("Difficulty: {} | Attempts: {} | Hints: {}", 
+                        println!("Difficulty: {} | Attempts: {} | Hints: {}",
                                  DIFFICULTY_DESCRIPTIONS[state.difficulty].yellow(),
                                  state.attempts.to_string().yellow(),
                                  state.hints_used.to_string().yellow());
-                        
+
                         print_fancy_message("The Ancient Guardian's riddle:", "yellow");
                         println!("{}", state.current_riddle.bright_white());
                         
                         // Continue riddle solving loop
                         loop {
                             println!("\n{}", "-".repeat(50).bright_blue());
-                            println!("Attempts: {} | Hints Used: {} | Score: {}", 
+                            println!("Attempts: {} | Hint Tier: {}/{} | Score: {}",
                                      state.attempts.to_string().yellow(),
                                      state.hints_used.to_string().yellow(),
+                                     HINT_TIERS.len(),
                                      state.score.to_string().green());
                             println!("{}", "-".repeat(50).bright_blue());
                             
@@ -421,31 +755,36 @@ async fn main() -> Result<(), Box<dyn Error>> {
                                 .interact_text()?;
                             
                             if guess.trim().to_lowercase() == "hint" {
-                                let hint = get_hint(&riddler, &mut state).await?;
-                                print_fancy_message("The Guardian whispers a hint:", "magenta");
-                                println!("{}", hint.bright_white());
+                                let _hint = get_hint(&riddler, &mut state).await?;
                                 continue;
                             }
-                            
+
                             if guess.trim().to_lowercase() == "riddle" {
                                 print_fancy_message("The Guardian repeats the riddle:", "yellow");
                                 println!("{}", state.current_riddle.bright_white());
                                 continue;
                             }
-                            
+
                             // Check the guess
                             let correct = check_guess(&riddler, &guess, &mut state).await?;
-                            
+
                             if correct {
                                 print_fancy_message("CORRECT!", "green");
                                 println!("{}", "The Ancient Guardian nods in approval...".bright_green());
-                                
+
                                 // Get the final insight
-                                let insight = reveal_insight(&riddler, &mut state).await?;
-                                
-                                print_fancy_message("The Guardian reveals the promised wisdom:", "cyan");
-                                println!("{}", insight.bright_white());
-                                
+                                let _insight = reveal_insight(&riddler, &mut state).await?;
+
+                                review::record_solved_riddle(
+                                    &riddler,
+                                    &state.current_riddle,
+                                    state.history.clone(),
+                                    state.attempts,
+                                    state.hints_used,
+                                    &mut reviews,
+                                )
+                                .await?;
+
                                 println!("\n{} {}", "Final Score:".bright_yellow(), state.score.to_string().bright_green());
                                 
                                 println!("\nWould you like to play another riddle?");
@@ -463,6 +802,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
                             } else {
                                 print_fancy_message("INCORRECT!", "red");
                                 println!("{}", "The Ancient Guardian shakes their head. Try again...".bright_red());
+                                feedback::feedback_on_guess(&riddler, &guess, &mut state).await?;
                             }
                         }
                     }
@@ -472,7 +812,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
                     }
                 }
             }
-            2 => {
+            4 => {
                 // View Instructions
                 print_fancy_message("HOW TO PLAY", "blue");
                 println!("{}", "Welcome, seeker of ancient wisdom!".bright_cyan());
@@ -485,7 +825,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 println!("• Automatic game saving");
                 
                 println!("\n{}", "Commands during play:".bright_yellow());
-                println!("• Type 'hint' to request a hint (reduces score)");
+                println!("• Type 'hint' to request a hint (each tier costs more: 5/10/20 points, capped)");
                 println!("• Type 'riddle' to see the riddle again");
                 
                 println!("\n{}", "Press Enter to return to the main menu...".bright_cyan());
@@ -494,7 +834,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
                     .allow_empty(true)
                     .interact_text()?;
             }
-            3 => {
+            5 => {
                 // Quit
                 print_fancy_message("Farewell, seeker of wisdom!", "cyan");
                 thread::sleep(Duration::from_secs(1));
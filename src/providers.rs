@@ -0,0 +1,113 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use rig::{
+    providers::{anthropic, openai},
+    streaming::StreamingChat,
+};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+const PREAMBLE: &str = "You are a guardian of an immense and powerful ancient secret. You are endowed with the unique ability to create incredibly challenging and intellectually stimulating riddles. You will ensure the user gets the riddle right before you let them get the treasure, which is actually a deep and stimulating truth relating to the riddle answer. When asked to give a hint, honor the specific hint instruction you are given, escalating in how much you reveal as instructed, but never state the literal answer outright unless explicitly told the hint may do so. Your responses should be mystical, ancient, and fitting for a wise guardian of secrets. For hints, be enigmatic but helpful.";
+
+/// The rig-supported backends the guardian can be built from.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Provider {
+    OpenAI,
+    Anthropic,
+}
+
+impl Provider {
+    fn name(&self) -> &'static str {
+        match self {
+            Provider::OpenAI => "OpenAI",
+            Provider::Anthropic => "Anthropic",
+        }
+    }
+
+    fn default_model(&self) -> &'static str {
+        match self {
+            Provider::OpenAI => "gpt-4o",
+            Provider::Anthropic => "claude-3-5-sonnet-20241022",
+        }
+    }
+}
+
+/// The provider, model, and temperature a guardian was built with, persisted
+/// alongside a save so continuing a game reuses the same guardian.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RiddlerConfig {
+    pub provider: Provider,
+    pub model: String,
+    pub temperature: f64,
+}
+
+impl Default for RiddlerConfig {
+    fn default() -> Self {
+        Self {
+            provider: Provider::OpenAI,
+            model: Provider::OpenAI.default_model().to_string(),
+            temperature: 0.9,
+        }
+    }
+}
+
+/// Picks a `RiddlerConfig` from the env vars when `RIDDLER_PROVIDER` is set (with
+/// `RIDDLER_MODEL`/`RIDDLER_TEMPERATURE` optional and defaulted), otherwise prompts the
+/// user with a startup `Select`/`Input` menu.
+pub fn select_config() -> Result<RiddlerConfig, Box<dyn Error>> {
+    if let Ok(provider_env) = std::env::var("RIDDLER_PROVIDER") {
+        let provider = match provider_env.to_lowercase().as_str() {
+            "anthropic" => Provider::Anthropic,
+            _ => Provider::OpenAI,
+        };
+        let model = std::env::var("RIDDLER_MODEL").unwrap_or_else(|_| provider.default_model().to_string());
+        let temperature = std::env::var("RIDDLER_TEMPERATURE")
+            .ok()
+            .and_then(|t| t.parse().ok())
+            .unwrap_or(0.9);
+
+        return Ok(RiddlerConfig { provider, model, temperature });
+    }
+
+    let providers = [Provider::OpenAI, Provider::Anthropic];
+    let names: Vec<&str> = providers.iter().map(Provider::name).collect();
+    let selection = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("Choose your guardian's provider:")
+        .default(0)
+        .items(&names)
+        .interact()?;
+    let provider = providers[selection];
+
+    let model: String = Input::with_theme(&ColorfulTheme::default())
+        .with_prompt("Model name")
+        .default(provider.default_model().to_string())
+        .interact_text()?;
+
+    let temperature: f64 = Input::with_theme(&ColorfulTheme::default())
+        .with_prompt("Temperature")
+        .default(0.9)
+        .interact_text()?;
+
+    Ok(RiddlerConfig { provider, model, temperature })
+}
+
+/// Builds the guardian from `config`, dispatching to whichever rig backend was chosen.
+pub fn build_riddler(config: &RiddlerConfig) -> Result<Box<dyn StreamingChat>, Box<dyn Error>> {
+    let riddler: Box<dyn StreamingChat> = match config.provider {
+        Provider::OpenAI => Box::new(
+            openai::Client::from_env()
+                .agent(&config.model)
+                .preamble(PREAMBLE)
+                .temperature(config.temperature)
+                .build(),
+        ),
+        Provider::Anthropic => Box::new(
+            anthropic::Client::from_env()
+                .agent(&config.model)
+                .preamble(PREAMBLE)
+                .temperature(config.temperature)
+                .build(),
+        ),
+    };
+
+    Ok(riddler)
+}
@@ -0,0 +1,120 @@
+use crate::guardian_chat_silent;
+use chrono::{DateTime, Duration as ChronoDuration, Local};
+use rig::streaming::StreamingChat;
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fs, path::Path};
+
+const REVIEW_FILE: &str = "riddler_reviews.json";
+
+/// A previously solved riddle, scheduled for spaced repetition via SM-2.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ReviewItem {
+    pub riddle: String,
+    pub answer: String,
+    pub ef: f64,
+    pub interval: u32,
+    pub repetitions: u32,
+    pub due: DateTime<Local>,
+}
+
+impl ReviewItem {
+    fn new(riddle: String, answer: String) -> Self {
+        Self {
+            riddle,
+            answer,
+            ef: 2.5,
+            interval: 0,
+            repetitions: 0,
+            due: Local::now(),
+        }
+    }
+}
+
+pub fn load_reviews() -> Result<Vec<ReviewItem>, Box<dyn Error>> {
+    if Path::new(REVIEW_FILE).exists() {
+        let json = fs::read_to_string(REVIEW_FILE)?;
+        Ok(serde_json::from_str(&json)?)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
+pub fn save_reviews(reviews: &[ReviewItem]) -> Result<(), Box<dyn Error>> {
+    let json = serde_json::to_string_pretty(reviews)?;
+    fs::write(REVIEW_FILE, json)?;
+    Ok(())
+}
+
+/// Applies the SM-2 algorithm to `item` for a recall quality `q` (0..=5), updating
+/// its ease factor, interval, repetition count, and next due date in place.
+pub fn schedule_review(item: &mut ReviewItem, q: u8) {
+    let q = q.min(5) as f64;
+
+    if q < 3.0 {
+        item.repetitions = 0;
+        item.interval = 1;
+    } else {
+        item.interval = match item.repetitions {
+            0 => 1,
+            1 => 6,
+            _ => (item.interval as f64 * item.ef).round() as u32,
+        };
+        item.repetitions += 1;
+    }
+
+    item.ef = (item.ef + (0.1 - (5.0 - q) * (0.08 + (5.0 - q) * 0.02))).max(1.3);
+    item.due = Local::now() + ChronoDuration::days(item.interval as i64);
+}
+
+/// Derives an SM-2 recall-quality grade (0..=5) from how much help a guess needed.
+pub fn quality_from_performance(attempts: usize, hints_used: usize) -> u8 {
+    match (attempts, hints_used) {
+        (1, 0) => 5,
+        (_, 0) if attempts <= 2 => 4,
+        (_, h) if h <= 1 => 3,
+        (_, h) if h == 2 => 2,
+        _ => 1,
+    }
+}
+
+/// Returns the items currently due for review, oldest due date first.
+pub fn due_items(reviews: &[ReviewItem]) -> Vec<ReviewItem> {
+    let now = Local::now();
+    let mut due: Vec<ReviewItem> = reviews.iter().filter(|item| item.due <= now).cloned().collect();
+    due.sort_by_key(|item| item.due);
+    due
+}
+
+/// Records a just-solved riddle into the review library, scheduling (or rescheduling)
+/// it with SM-2 based on how many attempts and hints the player needed.
+pub async fn record_solved_riddle(
+    riddler: &dyn StreamingChat,
+    riddle: &str,
+    history: Vec<rig::completion::Message>,
+    attempts: usize,
+    hints_used: usize,
+    reviews: &mut Vec<ReviewItem>,
+) -> Result<(), Box<dyn Error>> {
+    let answer_prompt =
+        "Please state the canonical answer to the riddle in a short phrase, nothing more.";
+    let answer = guardian_chat_silent(
+        riddler,
+        answer_prompt,
+        history,
+        "Failed to record the answer for review",
+        "The Guardian inscribes the riddle for later review...",
+    )
+    .await?;
+
+    let q = quality_from_performance(attempts, hints_used);
+    match reviews.iter_mut().find(|item| item.riddle == riddle) {
+        Some(item) => schedule_review(item, q),
+        None => {
+            let mut item = ReviewItem::new(riddle.to_string(), answer.trim().to_string());
+            schedule_review(&mut item, q);
+            reviews.push(item);
+        }
+    }
+
+    save_reviews(reviews)
+}
@@ -0,0 +1,86 @@
+use crate::GameState;
+use std::{error::Error, fs, path::Path};
+
+const SAVES_DIR: &str = "saves";
+
+/// Sentinel slot used by campaign mode's underlying `GameState`. Campaign progress is
+/// already persisted via `story::save_campaign`, so this slot is never written to disk
+/// and is filtered out of the save browser if it's ever seen.
+pub const CAMPAIGN_SLOT: &str = "__campaign__";
+
+fn slot_path(slot: &str) -> String {
+    format!("{}/{}.json", SAVES_DIR, slot)
+}
+
+/// Persists `state` to its named slot, writing to a temp file first so a crash
+/// mid-write can't corrupt the save. A no-op for the campaign sentinel slot, since
+/// campaign games are saved separately and shouldn't show up in the save browser.
+pub fn save_slot(slot: &str, state: &GameState) -> Result<(), Box<dyn Error>> {
+    if slot == CAMPAIGN_SLOT {
+        return Ok(());
+    }
+
+    fs::create_dir_all(SAVES_DIR)?;
+
+    let path = slot_path(slot);
+    let temp_path = format!("{}.tmp", path);
+    let json = serde_json::to_string_pretty(state)?;
+    fs::write(&temp_path, &json)?;
+
+    if Path::new(&path).exists() {
+        fs::remove_file(&path)?;
+    }
+    fs::rename(&temp_path, &path)?;
+
+    Ok(())
+}
+
+pub fn load_slot(slot: &str) -> Result<GameState, Box<dyn Error>> {
+    let json = fs::read_to_string(slot_path(slot))?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+pub fn delete_slot(slot: &str) -> Result<(), Box<dyn Error>> {
+    fs::remove_file(slot_path(slot))?;
+    Ok(())
+}
+
+/// A row in the save browser: the slot name plus the fields worth showing without
+/// fully resuming the game.
+pub struct SlotSummary {
+    pub slot: String,
+    pub difficulty: usize,
+    pub score: i32,
+    pub date_started: String,
+}
+
+/// Lists every save slot under `saves/`, sorted by slot name. Slots that fail to
+/// parse are skipped rather than aborting the whole listing.
+pub fn list_slots() -> Result<Vec<SlotSummary>, Box<dyn Error>> {
+    fs::create_dir_all(SAVES_DIR)?;
+
+    let mut slots = Vec::new();
+    for entry in fs::read_dir(SAVES_DIR)? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+        let Some(slot) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        if slot == CAMPAIGN_SLOT {
+            continue;
+        }
+        if let Ok(state) = load_slot(slot) {
+            slots.push(SlotSummary {
+                slot: slot.to_string(),
+                difficulty: state.difficulty,
+                score: state.score,
+                date_started: state.date_started,
+            });
+        }
+    }
+
+    slots.sort_by(|a, b| a.slot.cmp(&b.slot));
+    Ok(slots)
+}
@@ -0,0 +1,95 @@
+use crate::{guardian_chat_silent, GameState};
+use rig::{completion::Message, streaming::StreamingChat};
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fs, path::Path};
+
+const CAMPAIGN_SAVE_FILE: &str = "riddler_campaign.json";
+
+/// A branch out of a `StoryBlock`, taken once the guardian judges which one the
+/// player's answer best matches.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Option_ {
+    pub answer_summary: String,
+    pub next: String,
+}
+
+/// One node of a branching story graph. An empty `options` list marks a terminal block.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StoryBlock {
+    pub id: String,
+    pub narration: String,
+    pub riddle_prompt: String,
+    pub options: Vec<Option_>,
+}
+
+/// Campaign progress: the underlying riddle game plus which story block is active.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CampaignState {
+    pub game: GameState,
+    pub current_block: String,
+}
+
+/// Loads a branching story graph from a TOML or JSON file, picking the format by
+/// the file's extension (defaulting to TOML). A JSON file holds an array of blocks; a
+/// TOML file lists them as `[[blocks]]` tables.
+pub fn load_story(path: &str) -> Result<Vec<StoryBlock>, Box<dyn Error>> {
+    #[derive(Deserialize)]
+    struct StoryFile {
+        blocks: Vec<StoryBlock>,
+    }
+
+    let contents = fs::read_to_string(path)?;
+    let blocks = match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+        Some("json") => serde_json::from_str(&contents)?,
+        _ => toml::from_str::<StoryFile>(&contents)?.blocks,
+    };
+    Ok(blocks)
+}
+
+pub fn find_block<'a>(blocks: &'a [StoryBlock], id: &str) -> Option<&'a StoryBlock> {
+    blocks.iter().find(|block| block.id == id)
+}
+
+pub fn save_campaign(state: &CampaignState) -> Result<(), Box<dyn Error>> {
+    let json = serde_json::to_string_pretty(state)?;
+    fs::write(CAMPAIGN_SAVE_FILE, json)?;
+    Ok(())
+}
+
+/// Asks the guardian which of `block`'s options the judged answer best fits, falling
+/// back to the first option if its response can't be parsed as an index.
+pub async fn pick_next_block(
+    riddler: &dyn StreamingChat,
+    history: Vec<Message>,
+    block: &StoryBlock,
+) -> Result<String, Box<dyn Error>> {
+    if block.options.len() == 1 {
+        return Ok(block.options[0].next.clone());
+    }
+
+    let listing = block
+        .options
+        .iter()
+        .enumerate()
+        .map(|(i, option)| format!("{}: {}", i, option.answer_summary))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let prompt = format!(
+        "Given the user's answer, which of these story branches fits best? Respond with only the number, nothing more.\n{}",
+        listing
+    );
+
+    let response = guardian_chat_silent(
+        riddler,
+        &prompt,
+        history,
+        "Failed to choose the next story branch",
+        "The Guardian weighs how the tale should turn...",
+    )
+    .await?;
+
+    let index = response.trim().parse::<usize>().unwrap_or(0);
+    let chosen = block.options.get(index).unwrap_or(&block.options[0]);
+    Ok(chosen.next.clone())
+}